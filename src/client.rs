@@ -1,15 +1,25 @@
-use reqwest::{Client, Error};
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::{Client, ClientBuilder, Error, Response, StatusCode, header::HeaderMap};
 
 use crate::{
     Civilization, SortBy,
     query::Query,
+    ratelimit::RateLimiter,
     types::{BuildOrder, BuildOrders, Status},
 };
 
 const BASE_URI: &str = "https://aoe4guides.com/api";
 
+/// Base delay used for exponential backoff on retried 5xx responses
+const BACKOFF_BASE: Duration = Duration::from_millis(200);
+
 pub struct OrdaClient {
     http: Client,
+    base_url: String,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    max_retries: u32,
 }
 
 impl OrdaClient {
@@ -18,15 +28,66 @@ impl OrdaClient {
     /// This constructor initializes a reusable `reqwest::Client`
     /// that maintains connection pools and reduces overhead
     ///
+    /// Equivalent to `OrdaClient::builder().build()`. Use [`OrdaClient::builder`]
+    /// directly to customize the base URL, timeout, user agent, or headers
+    ///
     /// # Example
     /// ```
-    /// use orda::OrdaClient;
+    /// use yurt::OrdaClient;
     ///
     /// let client = OrdaClient::new();
     /// ```
     pub fn new() -> Self {
-        Self {
-            http: Client::new(),
+        Self::builder()
+            .build()
+            .expect("default OrdaClient configuration should never fail to build")
+    }
+
+    /// Creates an [`OrdaClientBuilder`] for configuring a custom [`OrdaClient`]
+    ///
+    /// # Example
+    /// ```
+    /// use yurt::OrdaClient;
+    /// use std::time::Duration;
+    ///
+    /// let client = OrdaClient::builder()
+    ///     .user_agent("my-app/1.0")
+    ///     .timeout(Duration::from_secs(5))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn builder() -> OrdaClientBuilder {
+        OrdaClientBuilder::new()
+    }
+
+    /// Sends a request, throttling it through the configured rate limiter
+    /// (if any) and retrying on `429` or transient `5xx` responses
+    ///
+    /// The delay comes from the `Retry-After` header (seconds or HTTP-date)
+    /// when the response carries a usable one, on `429` and `5xx` alike;
+    /// otherwise exponential backoff is used. Retries stop
+    /// once `max_retries` attempts have been made, returning the last
+    /// response either way.
+    async fn execute(&self, request: reqwest::RequestBuilder) -> Result<Response, Error> {
+        let mut attempt = 0;
+
+        loop {
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire().await;
+            }
+
+            let request = request
+                .try_clone()
+                .expect("OrdaClient never sends streaming request bodies");
+            let response = request.send().await?;
+
+            if attempt >= self.max_retries || !should_retry(response.status()) {
+                return Ok(response);
+            }
+
+            let delay = retry_after(response.headers()).unwrap_or_else(|| backoff(attempt));
+            tokio::time::sleep(delay).await;
+            attempt += 1;
         }
     }
 
@@ -43,7 +104,7 @@ impl OrdaClient {
     ///
     /// # Example
     /// ```
-    /// use orda::OrdaClient;
+    /// use yurt::OrdaClient;
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -56,12 +117,9 @@ impl OrdaClient {
     /// # }
     /// ```
     pub async fn get_status(&self) -> Result<Status, Error> {
-        self.http
-            .get(format!("{BASE_URI}/status"))
-            .send()
-            .await?
-            .json::<Status>()
-            .await
+        let request = self.http.get(format!("{}/status", self.base_url));
+
+        self.execute(request).await?.json::<Status>().await
     }
 
     /// Fetches a list of build orders (maximum 10 results per request)
@@ -78,7 +136,7 @@ impl OrdaClient {
     ///
     /// # Example
     /// ```
-    /// use orda::{OrdaClient, Civilization, SortBy};
+    /// use yurt::{OrdaClient, Civilization, SortBy};
     ///
     /// #[tokio::main]
     /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -100,13 +158,35 @@ impl OrdaClient {
     ) -> Result<BuildOrders, Error> {
         let query = Query::from_parts(civ, order_by, overlay);
 
-        self.http
-            .get(format!("{BASE_URI}/builds"))
-            .query(&query)
-            .send()
-            .await?
-            .json::<BuildOrders>()
-            .await
+        self.get_builds_with(query).await
+    }
+
+    /// Fetches a list of build orders (maximum 10 results per request) using
+    /// a fully customized [`Query`]
+    ///
+    /// Unlike [`OrdaClient::get_builds`], this also supports filtering on
+    /// `map`, `season`, and `strategy` via [`Query::builder`]
+    ///
+    /// # Example
+    /// ```
+    /// use yurt::{OrdaClient, Query, Civilization};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = OrdaClient::new();
+    /// let query = Query::builder().civ(Civilization::Fre).map("Arabia").build();
+    ///
+    /// let builds = client.get_builds_with(query).await?;
+    ///
+    /// println!("Fetched {} builds (API max = 10)", builds.len());
+    ///
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_builds_with(&self, query: Query) -> Result<BuildOrders, Error> {
+        let request = self.http.get(format!("{}/builds", self.base_url)).query(&query);
+
+        self.execute(request).await?.json::<BuildOrders>().await
     }
 
     /// Fetches a single build order by its unique ID
@@ -119,7 +199,7 @@ impl OrdaClient {
     ///
     /// # Example
     /// ```
-    /// use orda::OrdaClient;
+    /// use yurt::OrdaClient;
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -138,14 +218,12 @@ impl OrdaClient {
     ) -> Result<BuildOrder, Error> {
         let build_id = build_id.into();
         let query = Query::from_parts(Civilization::Any, None, overlay);
+        let request = self
+            .http
+            .get(format!("{}/builds/{build_id}", self.base_url))
+            .query(&query);
 
-        self.http
-            .get(format!("{BASE_URI}/builds/{build_id}"))
-            .query(&query)
-            .send()
-            .await?
-            .json::<BuildOrder>()
-            .await
+        self.execute(request).await?.json::<BuildOrder>().await
     }
 
     /// Fetches the favorite build orders for a given user
@@ -160,7 +238,7 @@ impl OrdaClient {
     ///
     /// # Example
     /// ```no_run
-    /// use orda::{OrdaClient, Civilization, SortBy};
+    /// use yurt::{OrdaClient, Civilization, SortBy};
     ///
     /// # #[tokio::main]
     /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -184,19 +262,207 @@ impl OrdaClient {
     ) -> Result<BuildOrders, Error> {
         let user_id = user_id.into();
         let query = Query::from_parts(civ, order_by, overlay);
+        let request = self
+            .http
+            .get(format!("{}/favorites/{user_id}", self.base_url))
+            .query(&query);
 
-        self.http
-            .get(format!("{BASE_URI}/favorites/{user_id}"))
-            .query(&query)
-            .send()
-            .await?
-            .json::<BuildOrders>()
-            .await
+        self.execute(request).await?.json::<BuildOrders>().await
     }
 }
 
+/// Whether a response status warrants a retry: `429` or any `5xx`
+fn should_retry(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
+/// Parses the `Retry-After` header, which the API may send as either a
+/// number of seconds or an HTTP-date. Takes the header map directly (rather
+/// than a whole [`Response`]) so the parsing logic is testable in isolation
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(reqwest::header::RETRY_AFTER)?.to_str().ok()?;
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let target = httpdate::parse_http_date(value).ok()?;
+
+    target.duration_since(std::time::SystemTime::now()).ok()
+}
+
+/// Upper bound on the exponential backoff delay, so a large `max_retries`
+/// can't grow the wait into a practically infinite one
+const BACKOFF_MAX: Duration = Duration::from_secs(30);
+
+/// Exponential backoff delay for a transient `5xx` retry attempt
+fn backoff(attempt: u32) -> Duration {
+    BACKOFF_BASE
+        .checked_mul(2u32.saturating_pow(attempt))
+        .unwrap_or(BACKOFF_MAX)
+        .min(BACKOFF_MAX)
+}
+
 impl Default for OrdaClient {
     fn default() -> Self {
         Self::new()
     }
 }
+
+/// Builder for [`OrdaClient`], used to configure the base URL, timeout,
+/// user agent, and default headers of the underlying `reqwest::Client`
+///
+/// # Example
+/// ```
+/// use yurt::OrdaClient;
+/// use std::time::Duration;
+///
+/// let client = OrdaClient::builder()
+///     .base_url("http://localhost:8080/api")
+///     .timeout(Duration::from_secs(10))
+///     .build()
+///     .unwrap();
+/// ```
+pub struct OrdaClientBuilder {
+    base_url: String,
+    client_builder: ClientBuilder,
+    rate_limit: Option<(u32, f64)>,
+    max_retries: u32,
+}
+
+impl OrdaClientBuilder {
+    fn new() -> Self {
+        Self {
+            base_url: BASE_URI.to_string(),
+            client_builder: Client::builder(),
+            rate_limit: None,
+            max_retries: 0,
+        }
+    }
+
+    /// Overrides the API base URL (useful for pointing at a local mock server)
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Sets the request timeout of the underlying `reqwest::Client`
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.client_builder = self.client_builder.timeout(timeout);
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with every request
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.client_builder = self.client_builder.user_agent(user_agent.into());
+        self
+    }
+
+    /// Sets default headers sent with every request (e.g. auth headers for
+    /// future private endpoints)
+    pub fn default_headers(mut self, headers: HeaderMap) -> Self {
+        self.client_builder = self.client_builder.default_headers(headers);
+        self
+    }
+
+    /// Enables an opt-in token-bucket rate limiter: at most `capacity`
+    /// requests may burst at once, refilling at `refill_rate` tokens per
+    /// second. Requests await an available token before being sent, keeping
+    /// large loops (e.g. fetching many favorites) within upstream limits
+    ///
+    /// # Panics
+    /// Panics if `capacity` is `0` or if `refill_rate` is not a positive,
+    /// finite number.
+    pub fn rate_limit(mut self, capacity: u32, refill_rate: f64) -> Self {
+        assert!(capacity > 0, "capacity must be at least 1, got 0");
+        assert!(
+            refill_rate.is_finite() && refill_rate > 0.0,
+            "refill_rate must be a positive, finite number of tokens per second, got {refill_rate}"
+        );
+
+        self.rate_limit = Some((capacity, refill_rate));
+        self
+    }
+
+    /// Sets how many times to retry a request that receives a `429` or
+    /// transient `5xx` response before giving up. Defaults to `0` (no retry)
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Builds the configured [`OrdaClient`]
+    ///
+    /// # Errors
+    /// Returns an error if the underlying `reqwest::Client` fails to build
+    /// (e.g. an invalid TLS configuration)
+    pub fn build(self) -> Result<OrdaClient, Error> {
+        Ok(OrdaClient {
+            http: self.client_builder.build()?,
+            base_url: self.base_url,
+            rate_limiter: self
+                .rate_limit
+                .map(|(capacity, refill_rate)| Arc::new(RateLimiter::new(capacity, refill_rate))),
+            max_retries: self.max_retries,
+        })
+    }
+}
+
+impl Default for OrdaClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_after_parses_seconds() {
+        let mut headers = HeaderMap::new();
+        headers.insert(reqwest::header::RETRY_AFTER, "5".parse().unwrap());
+
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn retry_after_parses_future_http_date() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            reqwest::header::RETRY_AFTER,
+            "Tue, 01 Jan 2999 00:00:00 GMT".parse().unwrap(),
+        );
+
+        assert!(retry_after(&headers).is_some());
+    }
+
+    #[test]
+    fn retry_after_past_http_date_is_none() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            reqwest::header::RETRY_AFTER,
+            "Fri, 01 Jan 1999 00:00:00 GMT".parse().unwrap(),
+        );
+
+        assert_eq!(retry_after(&headers), None);
+    }
+
+    #[test]
+    fn retry_after_missing_header_is_none() {
+        assert_eq!(retry_after(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_cap() {
+        assert_eq!(backoff(0), BACKOFF_BASE);
+        assert_eq!(backoff(1), BACKOFF_BASE * 2);
+        assert_eq!(backoff(2), BACKOFF_BASE * 4);
+    }
+
+    #[test]
+    fn backoff_saturates_instead_of_overflowing() {
+        assert_eq!(backoff(u32::MAX), BACKOFF_MAX);
+        assert_eq!(backoff(100), BACKOFF_MAX);
+    }
+}
@@ -1,8 +1,12 @@
 mod client;
 mod query;
+mod ratelimit;
 mod types;
 
-pub use client::OrdaClient;
+pub use client::{OrdaClient, OrdaClientBuilder};
 pub use query::Civilization;
 pub use query::Query;
+pub use query::QueryBuilder;
 pub use query::SortBy;
+pub use types::BuildOrder;
+pub use types::Timestamp;
@@ -1,6 +1,6 @@
 use std::fmt::{Display, Formatter, Result};
 
-use serde::Serialize;
+use serde::{Deserialize, Deserializer, Serialize};
 
 /// Query parameters used when requesting build orders from the API
 ///
@@ -21,6 +21,18 @@ pub struct Query {
     #[serde(skip_serializing_if = "Option::is_none")]
     order_by: Option<String>,
 
+    /// Map filter (`?map=...`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    map: Option<String>,
+
+    /// Ranked ladder season filter (`?season=...`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    season: Option<String>,
+
+    /// Strategic focus filter (`?strategy=...`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    strategy: Option<String>,
+
     /// Whether to include overlay-specific data in the response (`?overlay=true`)
     ///
     /// The parameter is omitted when `false`, making `false` the implicit default
@@ -39,17 +51,34 @@ impl Query {
     /// use yurt::{Civilization, Query, SortBy};
     ///
     /// let query = Query::from_parts(Civilization::Mon, Some(SortBy::Score), false);
-    /// // => ?civ=FRE&orderBy=score
+    /// // => ?civ=MON&orderBy=score
     /// ```
     pub fn from_parts(civ: Civilization, order_by: Option<SortBy>, overlay: bool) -> Self {
-        Self {
-            civ: match civ {
-                Civilization::Any => None,
-                other => Some(other.to_string()),
-            },
-            order_by: order_by.map(|o| o.to_string()),
-            overlay,
+        let mut builder = Query::builder().civ(civ).overlay(overlay);
+
+        if let Some(order_by) = order_by {
+            builder = builder.order_by(order_by);
         }
+
+        builder.build()
+    }
+
+    /// Creates a [`QueryBuilder`] for filtering on `map`, `season`, and
+    /// `strategy` in addition to the fields [`Query::from_parts`] supports
+    ///
+    /// # Example
+    /// ```
+    /// use yurt::{Civilization, Query, SortBy};
+    ///
+    /// let query = Query::builder()
+    ///     .civ(Civilization::Mon)
+    ///     .order_by(SortBy::Score)
+    ///     .map("Arabia")
+    ///     .build();
+    /// // => ?civ=MON&orderBy=score&map=Arabia
+    /// ```
+    pub fn builder() -> QueryBuilder {
+        QueryBuilder::default()
     }
 }
 
@@ -58,10 +87,78 @@ fn is_false(b: &bool) -> bool {
     !*b
 }
 
+/// Fluent builder for [`Query`], exposing the full filtering surface
+/// (`civ`, `orderBy`, `map`, `season`, `strategy`, `overlay`) that
+/// [`Query::from_parts`] only partially covers
+#[derive(Debug, Default)]
+pub struct QueryBuilder {
+    civ: Option<Civilization>,
+    order_by: Option<SortBy>,
+    map: Option<String>,
+    season: Option<String>,
+    strategy: Option<String>,
+    overlay: bool,
+}
+
+impl QueryBuilder {
+    /// Sets the civilization filter (`Civilization::Any` applies no filter)
+    pub fn civ(mut self, civ: Civilization) -> Self {
+        self.civ = Some(civ);
+        self
+    }
+
+    /// Sets the sorting criterion
+    pub fn order_by(mut self, order_by: SortBy) -> Self {
+        self.order_by = Some(order_by);
+        self
+    }
+
+    /// Filters on the map the build order targets
+    pub fn map(mut self, map: impl Into<String>) -> Self {
+        self.map = Some(map.into());
+        self
+    }
+
+    /// Filters on the ranked ladder season the build order was created for
+    pub fn season(mut self, season: impl Into<String>) -> Self {
+        self.season = Some(season.into());
+        self
+    }
+
+    /// Filters on the build order's general strategic focus
+    pub fn strategy(mut self, strategy: impl Into<String>) -> Self {
+        self.strategy = Some(strategy.into());
+        self
+    }
+
+    /// Whether to include overlay-specific data in the response
+    pub fn overlay(mut self, overlay: bool) -> Self {
+        self.overlay = overlay;
+        self
+    }
+
+    /// Builds the configured [`Query`]
+    pub fn build(self) -> Query {
+        Query {
+            civ: match self.civ {
+                Some(Civilization::Any) | None => None,
+                Some(other) => Some(other.to_string()),
+            },
+            order_by: self.order_by.map(|o| o.to_string()),
+            map: self.map,
+            season: self.season,
+            strategy: self.strategy,
+            overlay: self.overlay,
+        }
+    }
+}
+
 /// Enumeration of available civilizations recognized by the API
 ///
 /// Each variant corresponds to a civilization code used in `/builds` queries
-/// and build order metadata
+/// and build order metadata. [`Civilization::Unknown`] is a fallback for
+/// codes the API returns that this crate doesn't recognize yet (e.g. a new
+/// DLC civilization), so deserialization never fails on schema drift
 #[derive(Debug, Clone)]
 pub enum Civilization {
     /// Any civilization (no filter)
@@ -131,6 +228,13 @@ pub enum Civilization {
 
     /// Zhu Xi's Legacy
     Zxl,
+
+    /// A civilization code not recognized by this version of the crate
+    ///
+    /// Keeps unrecognized future codes (e.g. a new DLC civ) round-tripping
+    /// through [`BuildOrder`](crate::BuildOrder) instead of failing
+    /// deserialization outright
+    Unknown(String),
 }
 
 impl Display for Civilization {
@@ -159,12 +263,49 @@ impl Display for Civilization {
             Civilization::Sen => "SEN",
             Civilization::Tug => "TUG",
             Civilization::Zxl => "ZXL",
+            Civilization::Unknown(code) => code,
         };
 
         f.write_str(s)
     }
 }
 
+impl<'de> Deserialize<'de> for Civilization {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let code = String::deserialize(deserializer)?;
+
+        Ok(match code.as_str() {
+            "ANY" => Civilization::Any,
+            "ABB" => Civilization::Abb,
+            "AYY" => Civilization::Ayy,
+            "BYZ" => Civilization::Byz,
+            "CHI" => Civilization::Chi,
+            "DEL" => Civilization::Del,
+            "ENG" => Civilization::Eng,
+            "FRE" => Civilization::Fre,
+            "GOL" => Civilization::Gol,
+            "HOL" => Civilization::Hol,
+            "HRE" => Civilization::Hre,
+            "JAP" => Civilization::Jap,
+            "JDA" => Civilization::Jda,
+            "KTE" => Civilization::Kte,
+            "MAC" => Civilization::Mac,
+            "MAL" => Civilization::Mal,
+            "MON" => Civilization::Mon,
+            "DRA" => Civilization::Dra,
+            "OTT" => Civilization::Ott,
+            "RUS" => Civilization::Rus,
+            "SEN" => Civilization::Sen,
+            "TUG" => Civilization::Tug,
+            "ZXL" => Civilization::Zxl,
+            other => Civilization::Unknown(other.to_string()),
+        })
+    }
+}
+
 /// Available sorting criteria for build order queries.
 ///
 /// Determines the order in which build orders are returned by the API
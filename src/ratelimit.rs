@@ -0,0 +1,124 @@
+use std::time::{Duration, Instant};
+
+use tokio::sync::Mutex;
+
+/// A token-bucket rate limiter used to throttle outgoing requests
+///
+/// Tokens are refilled continuously based on elapsed time rather than on a
+/// fixed tick, so bursts up to `capacity` are allowed while the long-run
+/// average stays at `refill_rate` tokens per second
+#[derive(Debug)]
+pub(crate) struct RateLimiter {
+    capacity: f64,
+    refill_rate: f64,
+    state: Mutex<State>,
+}
+
+#[derive(Debug)]
+struct State {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    /// Creates a rate limiter holding at most `capacity` tokens and
+    /// refilling at `refill_rate` tokens per second
+    ///
+    /// # Panics
+    /// Panics if `capacity` is `0` — an empty bucket could never refill past
+    /// the `0.0` cap, so [`RateLimiter::acquire`] would wait forever.
+    /// Panics if `refill_rate` is not a positive, finite number — a
+    /// non-positive rate would never refill the bucket and make
+    /// [`RateLimiter::acquire`] wait forever (or divide by zero)
+    pub(crate) fn new(capacity: u32, refill_rate: f64) -> Self {
+        assert!(capacity > 0, "capacity must be at least 1, got 0");
+        assert!(
+            refill_rate.is_finite() && refill_rate > 0.0,
+            "refill_rate must be a positive, finite number of tokens per second, got {refill_rate}"
+        );
+
+        Self {
+            capacity: capacity as f64,
+            refill_rate,
+            state: Mutex::new(State {
+                tokens: capacity as f64,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Waits until at least one token is available, then consumes it
+    pub(crate) async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().await;
+
+                state.tokens = refill(
+                    state.tokens,
+                    state.last_refill.elapsed(),
+                    self.refill_rate,
+                    self.capacity,
+                );
+                state.last_refill = Instant::now();
+
+                if state.tokens >= 1.0 {
+                    state.tokens -= 1.0;
+                    None
+                } else {
+                    let deficit = 1.0 - state.tokens;
+                    Some(Duration::from_secs_f64(deficit / self.refill_rate))
+                }
+            };
+
+            match wait {
+                None => return,
+                Some(duration) => tokio::time::sleep(duration).await,
+            }
+        }
+    }
+}
+
+/// Computes the token count after refilling for `elapsed` time, capped at
+/// `capacity`. Kept as a pure function so the refill math is testable
+/// without driving real time through [`RateLimiter::acquire`]
+fn refill(tokens: f64, elapsed: Duration, refill_rate: f64, capacity: f64) -> f64 {
+    (tokens + elapsed.as_secs_f64() * refill_rate).min(capacity)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refill_accumulates_partial_tokens() {
+        assert_eq!(refill(1.0, Duration::from_secs(1), 2.0, 10.0), 3.0);
+    }
+
+    #[test]
+    fn refill_caps_at_capacity() {
+        assert_eq!(refill(0.0, Duration::from_secs(10), 5.0, 3.0), 3.0);
+    }
+
+    #[test]
+    fn refill_is_noop_after_no_time() {
+        assert_eq!(refill(2.0, Duration::from_secs(0), 5.0, 10.0), 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "refill_rate")]
+    fn new_panics_on_zero_refill_rate() {
+        let _ = RateLimiter::new(1, 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "refill_rate")]
+    fn new_panics_on_negative_refill_rate() {
+        let _ = RateLimiter::new(1, -1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity")]
+    fn new_panics_on_zero_capacity() {
+        let _ = RateLimiter::new(0, 1.0);
+    }
+}
@@ -1,4 +1,11 @@
-use serde::Deserialize;
+use std::fmt;
+
+use serde::{
+    Deserialize, Deserializer,
+    de::{self, Visitor},
+};
+
+use crate::Civilization;
 
 /// Simple status payload returned by health/check endpoints
 #[derive(Debug, Deserialize)]
@@ -29,7 +36,7 @@ pub struct BuildOrder {
     pub author_uid: String,
 
     /// The civilization this build order is designed for
-    pub civ: Option<String>,
+    pub civ: Option<Civilization>,
 
     /// The number of comments left by users
     pub comments: Option<i64>,
@@ -91,6 +98,29 @@ pub struct Timestamp {
     pub nanoseconds: i64,
 }
 
+#[cfg(feature = "time")]
+impl Timestamp {
+    /// Converts this timestamp into a [`time::OffsetDateTime`]
+    ///
+    /// Returns `None` instead of panicking when `seconds` falls outside the
+    /// range representable by [`time::OffsetDateTime`], or when adding
+    /// `nanoseconds` would overflow that range
+    ///
+    /// # Example
+    /// ```
+    /// # use yurt::Timestamp;
+    /// # let timestamp = Timestamp { seconds: 0, nanoseconds: 0 };
+    /// if let Some(datetime) = timestamp.as_datetime() {
+    ///     println!("{datetime}");
+    /// }
+    /// ```
+    pub fn as_datetime(&self) -> Option<time::OffsetDateTime> {
+        let datetime = time::OffsetDateTime::from_unix_timestamp(self.seconds).ok()?;
+
+        datetime.checked_add(time::Duration::nanoseconds(self.nanoseconds))
+    }
+}
+
 /// A sequence of steps representing a single phase of a build order
 #[derive(Debug, Deserialize)]
 pub struct BuildOrderStep {
@@ -112,22 +142,28 @@ pub struct BuildOrderStep {
 #[derive(Debug, Deserialize)]
 pub struct DetailStep {
     /// Number of villagers
-    pub villagers: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_opt_count")]
+    pub villagers: Option<u32>,
 
     /// Number of builders
-    pub builders: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_opt_count")]
+    pub builders: Option<u32>,
 
     /// Number of villagers assigned to food
-    pub food: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_opt_count")]
+    pub food: Option<u32>,
 
     /// Number of villagers assigned to wood
-    pub wood: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_opt_count")]
+    pub wood: Option<u32>,
 
     /// Number of villagers assigned to stone
-    pub stone: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_opt_count")]
+    pub stone: Option<u32>,
 
     /// Number of villagers assigned to gold
-    pub gold: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_opt_count")]
+    pub gold: Option<u32>,
 
     /// Textual timestamp
     pub time: Option<String>,
@@ -136,5 +172,111 @@ pub struct DetailStep {
     pub description: Option<String>,
 }
 
+/// Deserializes a resource count the API may represent as either a JSON
+/// string (`"12"`) or a JSON number (`12`), tolerating empty strings and a
+/// missing field as `None`
+fn deserialize_opt_count<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct OptCountVisitor;
+
+    impl<'de> Visitor<'de> for OptCountVisitor {
+        type Value = Option<u32>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a string or number representing an integer count")
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(None)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_any(self)
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            if v.is_empty() {
+                return Ok(None);
+            }
+
+            v.parse::<u32>().map(Some).map_err(de::Error::custom)
+        }
+
+        fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            u32::try_from(v).map(Some).map_err(de::Error::custom)
+        }
+
+        fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            u32::try_from(v).map(Some).map_err(de::Error::custom)
+        }
+    }
+
+    deserializer.deserialize_option(OptCountVisitor)
+}
+
 /// A list of build orders returned by the API
 pub type BuildOrders = Vec<BuildOrder>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Wrapper {
+        #[serde(default, deserialize_with = "deserialize_opt_count")]
+        value: Option<u32>,
+    }
+
+    fn parse(json: &str) -> Result<Option<u32>, serde_json::Error> {
+        serde_json::from_str::<Wrapper>(json).map(|wrapper| wrapper.value)
+    }
+
+    #[test]
+    fn parses_string_count() {
+        assert_eq!(parse(r#"{"value": "12"}"#).unwrap(), Some(12));
+    }
+
+    #[test]
+    fn parses_numeric_count() {
+        assert_eq!(parse(r#"{"value": 12}"#).unwrap(), Some(12));
+    }
+
+    #[test]
+    fn empty_string_is_none() {
+        assert_eq!(parse(r#"{"value": ""}"#).unwrap(), None);
+    }
+
+    #[test]
+    fn missing_field_is_none() {
+        assert_eq!(parse("{}").unwrap(), None);
+    }
+
+    #[test]
+    fn negative_number_is_error() {
+        assert!(parse(r#"{"value": -1}"#).is_err());
+    }
+}